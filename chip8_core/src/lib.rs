@@ -1,3 +1,6 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
 pub const SCREEN_WIDTH: usize = 64;
 pub const SCREEN_HEIGHT: usize = 32;
 
@@ -27,6 +30,109 @@ const STACK_SIZE: usize = 16;
 const NUM_KEYS: usize = 16;
 const START_ADDR: u16 = 0x200; // Start of the program is at 0x200
 
+/// Toggles for opcode behaviors that differ between the original COSMAC VIP
+/// interpreter and later interpreters such as SCHIP. Defaults to the classic
+/// COSMAC VIP behavior.
+#[derive(Debug, Clone, Copy)]
+pub struct Quirks {
+    /// `8XY6`/`8XYE` shift VY into VX (`true`, classic) or shift VX in place (`false`, modern).
+    pub shift_uses_vy: bool,
+    /// `FX55`/`FX65` advance `i_reg` to `i + X + 1` (`true`, classic) or leave it unchanged (`false`, modern).
+    pub load_store_increments_i: bool,
+    /// `BNNN` adds VX to NNN (`true`, modern) instead of V0 (`false`, classic).
+    pub jump_with_offset_uses_vx: bool,
+}
+
+impl Default for Quirks {
+    fn default() -> Self {
+        Quirks {
+            shift_uses_vy: true,
+            load_store_increments_i: true,
+            jump_with_offset_uses_vx: false,
+        }
+    }
+}
+
+/// Errors an embedding application may need to surface instead of the
+/// emulator crashing outright.
+#[derive(Debug)]
+pub enum EmulatorError {
+    /// The ROM is too large to fit in RAM starting at [`START_ADDR`].
+    RomTooLarge { size: usize, capacity: usize },
+    /// A v register index outside of `0..=15` was used.
+    InvalidRegister(usize),
+    /// No opcode arm matched.
+    UnknownOpcode(u16),
+    /// `push` was called with the call stack already full.
+    StackOverflow,
+    /// `pop` was called with an empty call stack.
+    StackUnderflow,
+    /// The program counter leaves no room in RAM for a full two-byte opcode
+    /// (it points at the last byte of RAM or past it).
+    ProgramCounterOutOfBounds(u16),
+    /// An opcode tried to read or write RAM outside of `0..RAM_SIZE`.
+    MemoryOutOfBounds(usize),
+    /// Reading the ROM file from disk failed.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for EmulatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmulatorError::RomTooLarge { size, capacity } => write!(
+                f,
+                "ROM of {} bytes does not fit in the {} bytes of RAM available from START_ADDR",
+                size, capacity
+            ),
+            EmulatorError::InvalidRegister(v_reg) => write!(
+                f,
+                "v register value {} not accessible. Must be a value from 0 to 15.",
+                v_reg
+            ),
+            EmulatorError::UnknownOpcode(opcode) => {
+                write!(f, "unimplemented operation code: 0x{:04X}", opcode)
+            }
+            EmulatorError::StackOverflow => {
+                write!(f, "stack overflow: no space left to push onto the call stack")
+            }
+            EmulatorError::StackUnderflow => {
+                write!(f, "stack underflow: no return address to pop")
+            }
+            EmulatorError::ProgramCounterOutOfBounds(pc) => {
+                write!(f, "program counter 0x{:04X} is out of bounds of RAM", pc)
+            }
+            EmulatorError::MemoryOutOfBounds(addr) => {
+                write!(f, "memory address 0x{:04X} is out of bounds of RAM", addr)
+            }
+            EmulatorError::Io(err) => write!(f, "failed to read ROM: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EmulatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EmulatorError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Audio output driven by the sound timer. Implement this to wire up a host
+/// application's audio backend; [`NoopBeeper`] is used by default.
+pub trait Beeper {
+    fn set_playing(&mut self, on: bool);
+}
+
+/// A [`Beeper`] that produces no sound. Used when an embedding application
+/// hasn't wired up an audio backend.
+#[derive(Debug, Default)]
+pub struct NoopBeeper;
+
+impl Beeper for NoopBeeper {
+    fn set_playing(&mut self, _on: bool) {}
+}
+
 pub struct Emulator {
     program_counter: u16,
     ram: [u8; RAM_SIZE],
@@ -38,6 +144,15 @@ pub struct Emulator {
     keys: [bool; NUM_KEYS],   // Keep track of which keys are pressed
     delay_timer: u8,
     sound_timer: u8,
+    quirks: Quirks,
+    rng: Box<dyn RngCore>,
+    beeper: Box<dyn Beeper>,
+}
+
+impl Default for Emulator {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Emulator {
@@ -53,31 +168,59 @@ impl Emulator {
             keys: [false; NUM_KEYS],
             delay_timer: 0,
             sound_timer: 0,
+            quirks: Quirks::default(),
+            rng: Box::new(rand::thread_rng()),
+            beeper: Box::new(NoopBeeper),
         };
         emu.ram[..FONTSET_SIZE].copy_from_slice(&FONTSET);
         emu
     }
 
+    /// Create an emulator backed by a seeded, deterministic RNG instead of
+    /// [`rand::thread_rng`]. Intended for tests that need a reproducible `CXNN` sequence.
+    pub fn with_rng(seed: u64) -> Self {
+        let mut emu = Self::new();
+        emu.rng = Box::new(StdRng::seed_from_u64(seed));
+        emu
+    }
+
+    /// Set the quirks/compatibility mode used to execute opcodes
+    pub fn set_quirks(&mut self, quirks: Quirks) {
+        self.quirks = quirks;
+    }
+
+    /// Builder-style variant of [`Emulator::set_quirks`]
+    pub fn with_quirks(mut self, quirks: Quirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Set the audio backend driven by the sound timer
+    pub fn set_beeper(&mut self, beeper: Box<dyn Beeper>) {
+        self.beeper = beeper;
+    }
+
+    /// Builder-style variant of [`Emulator::set_beeper`]
+    pub fn with_beeper(mut self, beeper: Box<dyn Beeper>) -> Self {
+        self.beeper = beeper;
+        self
+    }
+
     /// Set a v register to a specific value
-    fn set_v_reg(&mut self, v_reg: usize, value: u8) {
+    fn set_v_reg(&mut self, v_reg: usize, value: u8) -> Result<(), EmulatorError> {
         if v_reg > 15 {
-            panic!(
-                "v register value {} not accessible. Must be a value from 0 to 15.",
-                v_reg
-            );
+            return Err(EmulatorError::InvalidRegister(v_reg));
         }
-        self.v_reg[v_reg as usize] = value;
+        self.v_reg[v_reg] = value;
+        Ok(())
     }
 
     /// Get a v register value
-    fn get_v_reg(&self, v_reg: usize) -> u8 {
+    fn get_v_reg(&self, v_reg: usize) -> Result<u8, EmulatorError> {
         if v_reg > 15 {
-            panic!(
-                "v register value {} not accessible. Must be a value from 0 to 15.",
-                v_reg
-            );
+            return Err(EmulatorError::InvalidRegister(v_reg));
         }
-        self.v_reg[v_reg]
+        Ok(self.v_reg[v_reg])
     }
 
     /// Clear the screen: set all list values to false
@@ -86,15 +229,22 @@ impl Emulator {
     }
 
     /// Push a value to the stack and increase the stack_pointer with 1
-    fn push(&mut self, value: u16) {
+    fn push(&mut self, value: u16) -> Result<(), EmulatorError> {
+        if self.stack_pointer as usize >= STACK_SIZE {
+            return Err(EmulatorError::StackOverflow);
+        }
         self.stack[self.stack_pointer as usize] = value;
         self.stack_pointer += 1;
+        Ok(())
     }
 
     /// Pop a value from the stack and decrease the stack_pointer with 1
-    fn pop(&mut self) -> u16 {
+    fn pop(&mut self) -> Result<u16, EmulatorError> {
+        if self.stack_pointer == 0 {
+            return Err(EmulatorError::StackUnderflow);
+        }
         self.stack_pointer -= 1;
-        self.stack[self.stack_pointer as usize]
+        Ok(self.stack[self.stack_pointer as usize])
     }
 
     /// Reset the emulator to its original state
@@ -110,29 +260,72 @@ impl Emulator {
         self.delay_timer = 0;
         self.sound_timer = 0;
         self.ram[..FONTSET_SIZE].copy_from_slice(&FONTSET);
+        self.beeper.set_playing(false);
+    }
+
+    /// Copy a ROM into RAM starting at [`START_ADDR`]
+    pub fn load_rom(&mut self, bytes: &[u8]) -> Result<(), EmulatorError> {
+        let capacity = RAM_SIZE - START_ADDR as usize;
+        if bytes.len() > capacity {
+            return Err(EmulatorError::RomTooLarge {
+                size: bytes.len(),
+                capacity,
+            });
+        }
+        let start = START_ADDR as usize;
+        let end = start + bytes.len();
+        self.ram[start..end].copy_from_slice(bytes);
+        Ok(())
     }
 
-    pub fn tick(&mut self) {
-        let operation_code = self.fetch_opcode();
-        self.execute_opcode(operation_code);
+    /// Read a ROM file from disk and load it, for desktop callers that have
+    /// access to the filesystem.
+    pub fn load_rom_from_path(&mut self, path: impl AsRef<std::path::Path>) -> Result<(), EmulatorError> {
+        let bytes = std::fs::read(path).map_err(EmulatorError::Io)?;
+        self.load_rom(&bytes)
+    }
+
+    /// Set whether the key at `index` (0x0..=0xF on the standard hex keypad) is pressed
+    pub fn keypress(&mut self, index: usize, pressed: bool) {
+        if index < NUM_KEYS {
+            self.keys[index] = pressed;
+        }
+    }
+
+    pub fn tick(&mut self) -> Result<(), EmulatorError> {
+        let operation_code = self.fetch_opcode()?;
+        self.execute_opcode(operation_code)
     }
 
     /// Fetch the operation code.
     /// This is a combination of the u8 at the program_counter in ram and the next u8 since opcodes are 16 bit.
     /// The program_counter is then increased with 2 to point at the start of the next operation code.
-    fn fetch_opcode(&mut self) -> u16 {
+    fn fetch_opcode(&mut self) -> Result<u16, EmulatorError> {
+        if self.program_counter as usize + 1 >= RAM_SIZE {
+            return Err(EmulatorError::ProgramCounterOutOfBounds(
+                self.program_counter,
+            ));
+        }
         let higher_byte = self.ram[self.program_counter as usize] as u16;
         let lower_byte = self.ram[(self.program_counter + 1) as usize] as u16;
         let operation_code = (higher_byte << 8) | lower_byte;
         self.program_counter += 2;
-        operation_code
+        Ok(operation_code)
+    }
+
+    /// Check that `addr` is a valid RAM index, returning it for convenience.
+    fn checked_ram_addr(&self, addr: usize) -> Result<usize, EmulatorError> {
+        if addr >= RAM_SIZE {
+            return Err(EmulatorError::MemoryOutOfBounds(addr));
+        }
+        Ok(addr)
     }
 
     /// Execute the given operation code.
     ///
     /// This function transforms the given 16 bit opcode to 4 hexadecimal digits and matches the
     /// values to execute the expected operation.
-    fn execute_opcode(&mut self, opcode: u16) {
+    fn execute_opcode(&mut self, opcode: u16) -> Result<(), EmulatorError> {
         let digit1 = (opcode & 0xF000) >> 12;
         let digit2 = (opcode & 0x0F00) >> 8;
         let digit3 = (opcode & 0x00F0) >> 4;
@@ -140,14 +333,14 @@ impl Emulator {
 
         match (digit1, digit2, digit3, digit4) {
             // "NOP"; Do nothing
-            (0, 0, 0, 0) => return,
+            (0, 0, 0, 0) => return Ok(()),
             // Clear screen
             (0, 0, 0xE, 0) => {
                 self.clear_screen();
             }
             // Return from subroutine
             (0, 0, 0xE, 0xE) => {
-                self.program_counter = self.pop();
+                self.program_counter = self.pop()?;
             }
             // Jump to
             (1, _, _, _) => {
@@ -157,7 +350,7 @@ impl Emulator {
             // Call subroutine
             (2, _, _, _) => {
                 let nnn = opcode & 0xFFF;
-                self.push(self.program_counter);
+                self.push(self.program_counter)?;
                 self.program_counter = nnn;
             }
             // Skip if VX == 0xNN
@@ -189,72 +382,326 @@ impl Emulator {
             (6, _, _, _) => {
                 let x = digit2 as usize;
                 let nn = (opcode & 0xFF) as u8;
-                self.set_v_reg(x, nn);
+                self.set_v_reg(x, nn)?;
             }
 
             // VX += 0xNN
             (7, _, _, _) => {
                 let x = digit2 as usize;
                 let nn = (opcode & 0xFF) as u8;
-                self.set_v_reg(x, self.get_v_reg(x).wrapping_add(nn));
+                self.set_v_reg(x, self.get_v_reg(x)?.wrapping_add(nn))?;
             }
 
             // VX = VY
             (8, _, _, 0) => {
                 let x = digit2 as usize;
                 let y = digit3 as usize;
-                self.set_v_reg(x, self.get_v_reg(y));
-            }
-
-            /*
-            TODO:
-            8XY0 	VX = VY
-            8XY1 	VX |= VY
-            8XY2 	VX &= VY
-            8XY3 	VX ^= VY
-            8XY4 	VX += VY
-            8XY5 	VX -= VY
-            8XY6 	VX >>= 1
-            8XY7 	VX = VY - VX
-            8XYE 	VX <<= 1
-            9XY0 	Skip if VX != VY
-            ANNN 	I = 0xNNN
-            BNNN 	Jump to V0 + 0xNNN
-            CXNN 	VX = rand() & 0xNN
-            DXYN 	Draw sprite at (VX, VY)
-            EX9E 	Skip if key index in VX is pressed
-            EXA1 	Skip if key index in VX isn't pressed
-            FX07 	VX = Delay Timer
-            FX0A 	Waits for key press, stores index in VX
-            FX15 	Delay Timer = VX
-            FX18 	Sound Timer = VX
-            FX1E 	I += VX
-            FX29 	Set I to address of font character in VX
-            FX33 	Stores BCD encoding of VX into I
-            FX55 	Stores V0 thru VX into RAM address starting at I
-            FX65 	Fills V0 thru VX with RAM values starting at address in I
-            */
-            // In case opcode doesn't match, panic the program
-            (_, _, _, _) => unimplemented!(
-                "Unimplemented operation code: '{}' (hex='{}')",
-                opcode,
-                format!("{}{}{}{}", digit1, digit2, digit3, digit4)
-            ),
+                self.set_v_reg(x, self.get_v_reg(y)?)?;
+            }
+
+            // VX |= VY
+            (8, _, _, 1) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.set_v_reg(x, self.get_v_reg(x)? | self.get_v_reg(y)?)?;
+            }
+
+            // VX &= VY
+            (8, _, _, 2) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.set_v_reg(x, self.get_v_reg(x)? & self.get_v_reg(y)?)?;
+            }
+
+            // VX ^= VY
+            (8, _, _, 3) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                self.set_v_reg(x, self.get_v_reg(x)? ^ self.get_v_reg(y)?)?;
+            }
+
+            // VX += VY, VF = 1 on overflow
+            (8, _, _, 4) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let (result, overflow) = self.get_v_reg(x)?.overflowing_add(self.get_v_reg(y)?);
+                self.set_v_reg(x, result)?;
+                self.set_v_reg(0xF, overflow as u8)?;
+            }
+
+            // VX -= VY, VF = 1 when no borrow (VX >= VY)
+            (8, _, _, 5) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let (result, borrow) = self.get_v_reg(x)?.overflowing_sub(self.get_v_reg(y)?);
+                self.set_v_reg(x, result)?;
+                self.set_v_reg(0xF, !borrow as u8)?;
+            }
+
+            // VX >>= 1, VF = LSB of the shifted value before the shift
+            (8, _, _, 6) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let source = if self.quirks.shift_uses_vy {
+                    self.get_v_reg(y)?
+                } else {
+                    self.get_v_reg(x)?
+                };
+                self.set_v_reg(x, source >> 1)?;
+                self.set_v_reg(0xF, source & 1)?;
+            }
+
+            // VX = VY - VX, VF = 1 when no borrow (VY >= VX)
+            (8, _, _, 7) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let (result, borrow) = self.get_v_reg(y)?.overflowing_sub(self.get_v_reg(x)?);
+                self.set_v_reg(x, result)?;
+                self.set_v_reg(0xF, !borrow as u8)?;
+            }
+
+            // VX <<= 1, VF = MSB of the shifted value before the shift
+            (8, _, _, 0xE) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                let source = if self.quirks.shift_uses_vy {
+                    self.get_v_reg(y)?
+                } else {
+                    self.get_v_reg(x)?
+                };
+                self.set_v_reg(x, source << 1)?;
+                self.set_v_reg(0xF, (source >> 7) & 1)?;
+            }
+
+            // Skip if VX != VY
+            (9, _, _, 0) => {
+                let x = digit2 as usize;
+                let y = digit3 as usize;
+                if self.get_v_reg(x)? != self.get_v_reg(y)? {
+                    self.program_counter += 2;
+                }
+            }
+
+            // Jump to V0 (or VX, depending on the jump_with_offset_uses_vx quirk) + 0xNNN
+            (0xB, _, _, _) => {
+                let nnn = opcode & 0xFFF;
+                let offset = if self.quirks.jump_with_offset_uses_vx {
+                    self.get_v_reg(digit2 as usize)?
+                } else {
+                    self.get_v_reg(0)?
+                } as u16;
+                self.program_counter = nnn + offset;
+            }
+
+            // VX = rand() & NN
+            (0xC, _, _, _) => {
+                let x = digit2 as usize;
+                let nn = (opcode & 0xFF) as u8;
+                let random_byte = (self.rng.next_u32() & 0xFF) as u8;
+                self.set_v_reg(x, random_byte & nn)?;
+            }
+
+            // Draw sprite at (VX, VY) with height N, XOR'd onto the screen
+            (0xD, _, _, _) => {
+                let x = self.get_v_reg(digit2 as usize)? as usize;
+                let y = self.get_v_reg(digit3 as usize)? as usize;
+                let n = digit4 as usize;
+
+                self.set_v_reg(0xF, 0)?;
+                let mut collision = false;
+                for row in 0..n {
+                    let sprite_byte = self.ram[self.i_reg as usize + row];
+                    for col in 0..8 {
+                        if sprite_byte & (0x80 >> col) != 0 {
+                            let px = (x + col) % SCREEN_WIDTH;
+                            let py = (y + row) % SCREEN_HEIGHT;
+                            let idx = py * SCREEN_WIDTH + px;
+                            if self.screen[idx] {
+                                collision = true;
+                            }
+                            self.screen[idx] ^= true;
+                        }
+                    }
+                }
+                if collision {
+                    self.set_v_reg(0xF, 1)?;
+                }
+            }
+
+            // Skip if key index in VX is pressed
+            (0xE, _, 9, 0xE) => {
+                let x = self.get_v_reg(digit2 as usize)? as usize;
+                if self.keys.get(x).copied().unwrap_or(false) {
+                    self.program_counter += 2;
+                }
+            }
+
+            // Skip if key index in VX isn't pressed
+            (0xE, _, 0xA, 1) => {
+                let x = self.get_v_reg(digit2 as usize)? as usize;
+                if !self.keys.get(x).copied().unwrap_or(false) {
+                    self.program_counter += 2;
+                }
+            }
+
+            // Wait for a key press: block by re-executing this instruction until one is down
+            (0xF, _, 0, 0xA) => {
+                let x = digit2 as usize;
+                match self.keys.iter().position(|&pressed| pressed) {
+                    Some(index) => self.set_v_reg(x, index as u8)?,
+                    None => self.program_counter -= 2,
+                }
+            }
+
+            // VX = Delay Timer
+            (0xF, _, 0, 7) => {
+                let x = digit2 as usize;
+                self.set_v_reg(x, self.delay_timer)?;
+            }
+
+            // Delay Timer = VX
+            (0xF, _, 1, 5) => {
+                let x = digit2 as usize;
+                self.delay_timer = self.get_v_reg(x)?;
+            }
+
+            // Sound Timer = VX
+            (0xF, _, 1, 8) => {
+                let x = digit2 as usize;
+                self.sound_timer = self.get_v_reg(x)?;
+            }
+
+            // I += VX
+            (0xF, _, 1, 0xE) => {
+                let x = digit2 as usize;
+                self.i_reg = self.i_reg.wrapping_add(self.get_v_reg(x)? as u16);
+            }
+
+            // Set I to the address of the 5-byte font sprite for the hex digit in VX
+            (0xF, _, 2, 9) => {
+                let x = digit2 as usize;
+                self.i_reg = (self.get_v_reg(x)? & 0xF) as u16 * 5;
+            }
+
+            // Store the BCD encoding of VX into RAM starting at I
+            (0xF, _, 3, 3) => {
+                let x = digit2 as usize;
+                let value = self.get_v_reg(x)?;
+                let addr = self.checked_ram_addr(self.i_reg as usize + 2)?;
+                self.ram[addr - 2] = value / 100;
+                self.ram[addr - 1] = (value / 10) % 10;
+                self.ram[addr] = value % 10;
+            }
+
+            // Store V0 thru VX into RAM starting at I
+            (0xF, _, 5, 5) => {
+                let x = digit2 as usize;
+                self.checked_ram_addr(self.i_reg as usize + x)?;
+                for offset in 0..=x {
+                    self.ram[self.i_reg as usize + offset] = self.get_v_reg(offset)?;
+                }
+                if self.quirks.load_store_increments_i {
+                    self.i_reg += x as u16 + 1;
+                }
+            }
+
+            // Fill V0 thru VX with RAM values starting at address in I
+            (0xF, _, 6, 5) => {
+                let x = digit2 as usize;
+                self.checked_ram_addr(self.i_reg as usize + x)?;
+                for offset in 0..=x {
+                    self.set_v_reg(offset, self.ram[self.i_reg as usize + offset])?;
+                }
+                if self.quirks.load_store_increments_i {
+                    self.i_reg += x as u16 + 1;
+                }
+            }
+
+            // Set I to NNN
+            (0xA, _, _, _) => {
+                self.i_reg = opcode & 0xFFF;
+            }
+
+            // In case opcode doesn't match, return an error instead of panicking
+            (_, _, _, _) => return Err(EmulatorError::UnknownOpcode(opcode)),
         }
+        Ok(())
     }
 
     pub fn tick_timers(&mut self) {
         if self.delay_timer > 0 {
             self.delay_timer -= 1;
         }
-        match self.sound_timer {
-            0 => return,
-            1 => {
-                // TODO
-                println!("BING");
+        if self.sound_timer > 0 {
+            self.beeper.set_playing(true);
+            self.sound_timer -= 1;
+            if self.sound_timer == 0 {
+                self.beeper.set_playing(false);
             }
-            _ => {
-                self.sound_timer -= 1;
+        }
+    }
+
+    /// Whether the sound timer is currently active and audio should be playing
+    pub fn is_beeping(&self) -> bool {
+        self.sound_timer > 0
+    }
+}
+
+/// SDL2-backed square-wave [`Beeper`], matching the audio setup used by the
+/// sdl2 CHIP-8 frontends this crate targets.
+#[cfg(feature = "sdl2")]
+pub mod sdl2_beeper {
+    use super::Beeper;
+    use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
+
+    struct SquareWave {
+        phase_inc: f32,
+        phase: f32,
+        volume: f32,
+    }
+
+    impl AudioCallback for SquareWave {
+        type Channel = f32;
+
+        fn callback(&mut self, out: &mut [f32]) {
+            for sample in out.iter_mut() {
+                *sample = if self.phase <= 0.5 {
+                    self.volume
+                } else {
+                    -self.volume
+                };
+                self.phase = (self.phase + self.phase_inc) % 1.0;
+            }
+        }
+    }
+
+    /// Plays a square wave through an SDL2 audio device while active
+    pub struct Sdl2Beeper {
+        device: AudioDevice<SquareWave>,
+    }
+
+    impl Sdl2Beeper {
+        pub fn new(audio_subsystem: &sdl2::AudioSubsystem) -> Result<Self, String> {
+            let desired_spec = AudioSpecDesired {
+                freq: Some(44_100),
+                channels: Some(1),
+                samples: None,
+            };
+            let device = audio_subsystem.open_playback(None, &desired_spec, |spec| SquareWave {
+                phase_inc: 240.0 / spec.freq as f32,
+                phase: 0.0,
+                volume: 0.25,
+            })?;
+            Ok(Sdl2Beeper { device })
+        }
+    }
+
+    impl Beeper for Sdl2Beeper {
+        fn set_playing(&mut self, on: bool) {
+            if on {
+                self.device.resume();
+            } else {
+                self.device.pause();
             }
         }
     }
@@ -268,34 +715,123 @@ mod tests {
     #[test]
     fn set_v_reg() {
         let mut emul = Emulator::new();
-        emul.set_v_reg(12, 0x0089);
+        emul.set_v_reg(12, 0x0089).unwrap();
         assert_eq!(emul.v_reg[12], 0x0089);
     }
 
-    /// Test setting a non existing v register to a value using the dedicated method, which should panic
+    /// Test setting a non existing v register to a value using the dedicated method, which should error
     #[test]
-    #[should_panic]
     fn set_v_reg_invalid() {
         let mut emul = Emulator::new();
-        emul.set_v_reg(16, 0x0089);
+        assert!(matches!(
+            emul.set_v_reg(16, 0x0089),
+            Err(EmulatorError::InvalidRegister(16))
+        ));
+    }
+
+    /// Test loading a ROM that is too large to fit in RAM
+    #[test]
+    fn load_rom_too_large() {
+        let mut emul = Emulator::new();
+        let oversized_rom = vec![0u8; RAM_SIZE];
+        assert!(matches!(
+            emul.load_rom(&oversized_rom),
+            Err(EmulatorError::RomTooLarge { .. })
+        ));
+    }
+
+    /// Test that a ROM is copied into RAM starting at START_ADDR
+    #[test]
+    fn load_rom() {
+        let mut emul = Emulator::new();
+        emul.load_rom(&[0xAB, 0xCD]).unwrap();
+        assert_eq!(emul.ram[START_ADDR as usize], 0xAB);
+        assert_eq!(emul.ram[START_ADDR as usize + 1], 0xCD);
+    }
+
+    struct RecordingBeeper {
+        calls: std::rc::Rc<std::cell::RefCell<Vec<bool>>>,
+    }
+
+    impl Beeper for RecordingBeeper {
+        fn set_playing(&mut self, on: bool) {
+            self.calls.borrow_mut().push(on);
+        }
+    }
+
+    /// Test that is_beeping reflects whether the sound timer is active
+    #[test]
+    fn is_beeping_tracks_sound_timer() {
+        let mut emul = Emulator::new();
+        assert!(!emul.is_beeping());
+        emul.sound_timer = 2;
+        assert!(emul.is_beeping());
+    }
+
+    /// Test that tick_timers notifies the beeper while the sound timer is active,
+    /// and turns it off on the tick that brings the timer to zero
+    #[test]
+    fn tick_timers_drives_beeper() {
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut emul = Emulator::new().with_beeper(Box::new(RecordingBeeper {
+            calls: calls.clone(),
+        }));
+        emul.sound_timer = 2;
+
+        emul.tick_timers();
+        emul.tick_timers();
+
+        assert_eq!(*calls.borrow(), vec![true, true, false]);
+    }
+
+    /// Test that a single-tick beep (sound timer set to 1) still triggers an "on" notification
+    /// before turning off, instead of only ever notifying "off"
+    #[test]
+    fn tick_timers_beeps_for_single_tick_timer() {
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut emul = Emulator::new().with_beeper(Box::new(RecordingBeeper {
+            calls: calls.clone(),
+        }));
+        emul.sound_timer = 1;
+
+        emul.tick_timers();
+
+        assert_eq!(*calls.borrow(), vec![true, false]);
+    }
+
+    /// Test that reset stops an active beeper instead of leaving it playing
+    #[test]
+    fn reset_stops_beeper() {
+        let calls = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut emul = Emulator::new().with_beeper(Box::new(RecordingBeeper {
+            calls: calls.clone(),
+        }));
+        emul.sound_timer = 2;
+        emul.tick_timers();
+        assert_eq!(*calls.borrow(), vec![true]);
+
+        emul.reset();
+        assert_eq!(*calls.borrow(), vec![true, false]);
     }
 
     mod test_opcode_execution {
         use super::*;
 
-        /// Test non implemented opcode. This execution should panic.
+        /// Test non implemented opcode. This execution should return an error.
         #[test]
-        #[should_panic]
         fn non_implemented_opcode() {
             let mut emul = Emulator::new();
-            emul.execute_opcode(0x0011)
+            assert!(matches!(
+                emul.execute_opcode(0x0011),
+                Err(EmulatorError::UnknownOpcode(0x0011))
+            ));
         }
 
         /// Test do nothing opcode
         #[test]
         fn opcode_0000() {
             let mut emul = Emulator::new();
-            emul.execute_opcode(0x0000);
+            emul.execute_opcode(0x0000).unwrap();
         }
 
         /// Test clearing screen opcode
@@ -303,7 +839,7 @@ mod tests {
         fn opcode_00e0() {
             let mut emul = Emulator::new();
             emul.screen = [true; SCREEN_WIDTH * SCREEN_HEIGHT];
-            emul.execute_opcode(0x00E0);
+            emul.execute_opcode(0x00E0).unwrap();
             assert_eq!(emul.screen, [false; SCREEN_WIDTH * SCREEN_HEIGHT]);
         }
 
@@ -313,16 +849,26 @@ mod tests {
             let mut emul = Emulator::new();
             emul.stack[1] = 0x0011;
             emul.stack_pointer = 2;
-            emul.execute_opcode(0x00EE);
+            emul.execute_opcode(0x00EE).unwrap();
             assert_eq!(emul.stack_pointer, 1);
             assert_eq!(emul.program_counter, 0x0011);
         }
 
+        /// Test that returning from subroutine with an empty stack errors instead of panicking
+        #[test]
+        fn opcode_00ee_stack_underflow() {
+            let mut emul = Emulator::new();
+            assert!(matches!(
+                emul.execute_opcode(0x00EE),
+                Err(EmulatorError::StackUnderflow)
+            ));
+        }
+
         /// Test jump to
         #[test]
         fn opcode_1nnn() {
             let mut emul = Emulator::new();
-            emul.execute_opcode(0x1234);
+            emul.execute_opcode(0x1234).unwrap();
             assert_eq!(emul.program_counter, 0x234);
         }
 
@@ -331,21 +877,34 @@ mod tests {
         fn opcode_2nnn() {
             let mut emul = Emulator::new();
             emul.program_counter = 123;
-            emul.execute_opcode(0x2345);
+            emul.execute_opcode(0x2345).unwrap();
             assert_eq!(emul.program_counter, 0x345);
             assert_eq!(emul.stack[0], 123);
             assert_eq!(emul.stack_pointer, 1);
         }
 
+        /// Test that calling subroutines past the stack's capacity errors instead of panicking
+        #[test]
+        fn opcode_2nnn_stack_overflow() {
+            let mut emul = Emulator::new();
+            for _ in 0..STACK_SIZE {
+                emul.execute_opcode(0x2345).unwrap();
+            }
+            assert!(matches!(
+                emul.execute_opcode(0x2345),
+                Err(EmulatorError::StackOverflow)
+            ));
+        }
+
         /// Test skip if VX == 0xNN
         #[test]
         fn opcode_3nnn() {
             let mut emul = Emulator::new();
             emul.program_counter = 0;
             emul.v_reg[6] = 0x0078;
-            emul.execute_opcode(0x3678);
+            emul.execute_opcode(0x3678).unwrap();
             assert_eq!(emul.program_counter, 2); //v6 matches 0x0078, so program counter should increase by 2
-            emul.execute_opcode(0x3689);
+            emul.execute_opcode(0x3689).unwrap();
             assert_eq!(emul.program_counter, 2); //v6 does not match 0x0078, so program counter should not increase by 2
         }
 
@@ -354,10 +913,10 @@ mod tests {
         fn opcode_4nnn() {
             let mut emul = Emulator::new();
             emul.program_counter = 0;
-            emul.set_v_reg(11, 0x0033);
-            emul.execute_opcode(0x4B33);
+            emul.set_v_reg(11, 0x0033).unwrap();
+            emul.execute_opcode(0x4B33).unwrap();
             assert_eq!(emul.program_counter, 0); // v11 does match 0x0033, program counter should not increase
-            emul.execute_opcode(0x4B01);
+            emul.execute_opcode(0x4B01).unwrap();
             assert_eq!(emul.program_counter, 2); // v11 does not match 0x0001, program counter should increase with 2
         }
 
@@ -366,12 +925,12 @@ mod tests {
         fn opcode_5xy0() {
             let mut emul = Emulator::new();
             emul.program_counter = 0;
-            emul.set_v_reg(8, 0x0093);
-            emul.set_v_reg(15, 0x0093);
-            emul.execute_opcode(0x58F0);
+            emul.set_v_reg(8, 0x0093).unwrap();
+            emul.set_v_reg(15, 0x0093).unwrap();
+            emul.execute_opcode(0x58F0).unwrap();
             assert_eq!(emul.program_counter, 2); // v8 matches v15 => program counter should increase with 2
-            emul.set_v_reg(15, 0x0025);
-            emul.execute_opcode(0x58F0);
+            emul.set_v_reg(15, 0x0025).unwrap();
+            emul.execute_opcode(0x58F0).unwrap();
             assert_eq!(emul.program_counter, 2); // v8 does not match v15 => program counter should stay the same
         }
 
@@ -379,7 +938,7 @@ mod tests {
         #[test]
         fn opcode_6xnn() {
             let mut emul = Emulator::new();
-            emul.execute_opcode(0x6722);
+            emul.execute_opcode(0x6722).unwrap();
             assert_eq!(emul.v_reg[7], 0x0022);
         }
 
@@ -387,21 +946,407 @@ mod tests {
         #[test]
         fn opcode_7xnn() {
             let mut emul = Emulator::new();
-            emul.set_v_reg(7, 0x0055);
-            emul.execute_opcode(0x7733);
-            assert_eq!(emul.get_v_reg(7), 0x0088);
-            emul.execute_opcode(0x7780);
-            assert_eq!(emul.get_v_reg(7), 0x0008); // Test wrapping around
+            emul.set_v_reg(7, 0x0055).unwrap();
+            emul.execute_opcode(0x7733).unwrap();
+            assert_eq!(emul.get_v_reg(7).unwrap(), 0x0088);
+            emul.execute_opcode(0x7780).unwrap();
+            assert_eq!(emul.get_v_reg(7).unwrap(), 0x0008); // Test wrapping around
         }
 
         /// Test VX = VY
         #[test]
         fn opcode_8xy0() {
             let mut emul = Emulator::new();
-            emul.set_v_reg(2, 0x0055);
-            emul.set_v_reg(3, 0x0039);
-            emul.execute_opcode(0x8230);
-            assert_eq!(emul.get_v_reg(2), 0x0039);
+            emul.set_v_reg(2, 0x0055).unwrap();
+            emul.set_v_reg(3, 0x0039).unwrap();
+            emul.execute_opcode(0x8230).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x0039);
+        }
+
+        /// Test VX |= VY
+        #[test]
+        fn opcode_8xy1() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0x0F).unwrap();
+            emul.set_v_reg(3, 0xF0).unwrap();
+            emul.execute_opcode(0x8231).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0xFF);
+        }
+
+        /// Test VX &= VY
+        #[test]
+        fn opcode_8xy2() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0xFF).unwrap();
+            emul.set_v_reg(3, 0x0F).unwrap();
+            emul.execute_opcode(0x8232).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x0F);
+        }
+
+        /// Test VX ^= VY
+        #[test]
+        fn opcode_8xy3() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0xFF).unwrap();
+            emul.set_v_reg(3, 0x0F).unwrap();
+            emul.execute_opcode(0x8233).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0xF0);
+        }
+
+        /// Test VX += VY, including the overflow boundary
+        #[test]
+        fn opcode_8xy4() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0x01).unwrap();
+            emul.set_v_reg(3, 0x01).unwrap();
+            emul.execute_opcode(0x8234).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x02);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 0);
+            emul.set_v_reg(2, 0xFF).unwrap();
+            emul.set_v_reg(3, 0x01).unwrap();
+            emul.execute_opcode(0x8234).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x00);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 1);
+        }
+
+        /// Test VX -= VY, including the borrow boundary
+        #[test]
+        fn opcode_8xy5() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0x05).unwrap();
+            emul.set_v_reg(3, 0x01).unwrap();
+            emul.execute_opcode(0x8235).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x04);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 1);
+            emul.set_v_reg(2, 0x00).unwrap();
+            emul.set_v_reg(3, 0x01).unwrap();
+            emul.execute_opcode(0x8235).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0xFF);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 0);
+        }
+
+        /// Test VX >>= 1, VF receiving the shifted-out LSB
+        #[test]
+        fn opcode_8xy6() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(0, 0x03).unwrap(); // Classic quirk default: shift VY (here V0) into VX
+            emul.execute_opcode(0x8206).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x01);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 1);
+        }
+
+        /// Test VX = VY - VX, including the borrow boundary
+        #[test]
+        fn opcode_8xy7() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0x01).unwrap();
+            emul.set_v_reg(3, 0x05).unwrap();
+            emul.execute_opcode(0x8237).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x04);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 1);
+            emul.set_v_reg(2, 0x01).unwrap();
+            emul.set_v_reg(3, 0x00).unwrap();
+            emul.execute_opcode(0x8237).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0xFF);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 0);
+        }
+
+        /// Test VX <<= 1, VF receiving the shifted-out MSB
+        #[test]
+        fn opcode_8xye() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(0, 0x81).unwrap(); // Classic quirk default: shift VY (here V0) into VX
+            emul.execute_opcode(0x820E).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 0x02);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 1);
+        }
+
+        /// Test skip if VX != VY
+        #[test]
+        fn opcode_9xy0() {
+            let mut emul = Emulator::new();
+            emul.program_counter = 0;
+            emul.set_v_reg(2, 0x01).unwrap();
+            emul.set_v_reg(3, 0x02).unwrap();
+            emul.execute_opcode(0x9230).unwrap();
+            assert_eq!(emul.program_counter, 2);
+            emul.set_v_reg(3, 0x01).unwrap();
+            emul.execute_opcode(0x9230).unwrap();
+            assert_eq!(emul.program_counter, 2);
+        }
+
+        /// Test that the shift_uses_vy quirk controls whether 8XY6 shifts VY or VX
+        #[test]
+        fn opcode_8xy6_quirk_modes() {
+            let mut classic = Emulator::new();
+            classic.set_v_reg(3, 0x03).unwrap();
+            classic.set_v_reg(2, 0xFF).unwrap();
+            classic.execute_opcode(0x8236).unwrap();
+            assert_eq!(classic.get_v_reg(2).unwrap(), 0x01); // Shifted VY (V3) into VX
+
+            let mut modern = Emulator::new().with_quirks(Quirks {
+                shift_uses_vy: false,
+                ..Quirks::default()
+            });
+            modern.set_v_reg(3, 0xFF).unwrap();
+            modern.set_v_reg(2, 0x03).unwrap();
+            modern.execute_opcode(0x8236).unwrap();
+            assert_eq!(modern.get_v_reg(2).unwrap(), 0x01); // Shifted VX in place, VY ignored
+        }
+
+        /// Test jump to V0 + NNN (classic default quirk)
+        #[test]
+        fn opcode_bnnn_classic() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(0, 0x05).unwrap();
+            emul.set_v_reg(2, 0xFF).unwrap(); // Should be ignored under the classic quirk
+            emul.execute_opcode(0xB300).unwrap();
+            assert_eq!(emul.program_counter, 0x305);
+        }
+
+        /// Test jump to VX + NNN when the jump_with_offset_uses_vx quirk is enabled
+        #[test]
+        fn opcode_bnnn_modern() {
+            let mut emul = Emulator::new().with_quirks(Quirks {
+                jump_with_offset_uses_vx: true,
+                ..Quirks::default()
+            });
+            emul.set_v_reg(0, 0xFF).unwrap(); // Should be ignored under the modern quirk
+            emul.set_v_reg(3, 0x05).unwrap();
+            emul.execute_opcode(0xB300).unwrap();
+            assert_eq!(emul.program_counter, 0x305);
+        }
+
+        /// Test setting I to a literal address
+        #[test]
+        fn opcode_annn() {
+            let mut emul = Emulator::new();
+            emul.execute_opcode(0xA123).unwrap();
+            assert_eq!(emul.i_reg, 0x123);
+        }
+
+        /// Test that CXNN masks the random byte with NN
+        #[test]
+        fn opcode_cxnn_mask() {
+            let mut emul = Emulator::with_rng(42);
+            emul.execute_opcode(0xC000).unwrap(); // NN = 0x00 always clears VX regardless of the random byte
+            assert_eq!(emul.get_v_reg(0).unwrap(), 0x00);
+        }
+
+        /// Test that a seeded RNG produces the exact expected CXNN masked-value sequence
+        #[test]
+        fn opcode_cxnn_deterministic_with_seed() {
+            let mut emul = Emulator::with_rng(1234);
+            let mut values = Vec::new();
+            for _ in 0..5 {
+                emul.execute_opcode(0xC0FF).unwrap();
+                values.push(emul.get_v_reg(0).unwrap());
+            }
+            assert_eq!(values, vec![153, 145, 238, 21, 35]);
+        }
+
+        /// Test drawing a single-pixel sprite
+        #[test]
+        fn opcode_dxyn_single_pixel() {
+            let mut emul = Emulator::new();
+            emul.i_reg = 0x300;
+            emul.ram[0x300] = 0x80; // Single lit pixel in the top-left corner of the sprite row
+            emul.set_v_reg(0, 5).unwrap();
+            emul.set_v_reg(1, 5).unwrap();
+            emul.execute_opcode(0xD011).unwrap();
+            assert!(emul.screen[5 * SCREEN_WIDTH + 5]);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 0);
+        }
+
+        /// Test that drawing the same sprite twice flips the pixel back off and sets VF
+        #[test]
+        fn opcode_dxyn_collision() {
+            let mut emul = Emulator::new();
+            emul.i_reg = 0x300;
+            emul.ram[0x300] = 0x80;
+            emul.set_v_reg(0, 5).unwrap();
+            emul.set_v_reg(1, 5).unwrap();
+            emul.execute_opcode(0xD011).unwrap();
+            emul.execute_opcode(0xD011).unwrap();
+            assert!(!emul.screen[5 * SCREEN_WIDTH + 5]);
+            assert_eq!(emul.get_v_reg(0xF).unwrap(), 1);
+        }
+
+        /// Test that a sprite drawn near the right edge wraps around horizontally
+        #[test]
+        fn opcode_dxyn_horizontal_wrap() {
+            let mut emul = Emulator::new();
+            emul.i_reg = 0x300;
+            emul.ram[0x300] = 0xC0; // Two lit pixels at the left end of the sprite row
+            emul.set_v_reg(0, (SCREEN_WIDTH - 1) as u8).unwrap();
+            emul.set_v_reg(1, 0).unwrap();
+            emul.execute_opcode(0xD011).unwrap();
+            assert!(emul.screen[SCREEN_WIDTH - 1]);
+            assert!(emul.screen[0]);
+        }
+
+        /// Test skip if key index in VX is pressed
+        #[test]
+        fn opcode_ex9e() {
+            let mut emul = Emulator::new();
+            emul.program_counter = 0;
+            emul.set_v_reg(2, 5).unwrap();
+            emul.keypress(5, true);
+            emul.execute_opcode(0xE29E).unwrap();
+            assert_eq!(emul.program_counter, 2);
+            emul.keypress(5, false);
+            emul.execute_opcode(0xE29E).unwrap();
+            assert_eq!(emul.program_counter, 2);
+        }
+
+        /// Test skip if key index in VX isn't pressed
+        #[test]
+        fn opcode_exa1() {
+            let mut emul = Emulator::new();
+            emul.program_counter = 0;
+            emul.set_v_reg(2, 5).unwrap();
+            emul.execute_opcode(0xE2A1).unwrap();
+            assert_eq!(emul.program_counter, 2);
+            emul.keypress(5, true);
+            emul.execute_opcode(0xE2A1).unwrap();
+            assert_eq!(emul.program_counter, 2);
+        }
+
+        /// Test that an out-of-range key index in VX is treated as "not pressed" instead of panicking
+        #[test]
+        fn opcode_ex9e_exa1_out_of_range_key() {
+            let mut emul = Emulator::new();
+            emul.program_counter = 0;
+            emul.set_v_reg(2, 0xFF).unwrap();
+            emul.execute_opcode(0xE29E).unwrap();
+            assert_eq!(emul.program_counter, 0);
+            emul.execute_opcode(0xE2A1).unwrap();
+            assert_eq!(emul.program_counter, 2);
+        }
+
+        /// Test that FX0A busy-waits while no key is pressed and resumes once one is
+        #[test]
+        fn opcode_fx0a_busy_wait_and_resume() {
+            let mut emul = Emulator::new();
+            emul.program_counter = 10;
+            emul.execute_opcode(0xF20A).unwrap();
+            assert_eq!(emul.program_counter, 8); // Rewound to re-execute the same instruction
+            emul.keypress(7, true);
+            emul.execute_opcode(0xF20A).unwrap();
+            assert_eq!(emul.program_counter, 8); // Resumed without rewinding further
+            assert_eq!(emul.get_v_reg(2).unwrap(), 7);
+        }
+
+        /// Test VX = Delay Timer and Delay Timer = VX
+        #[test]
+        fn opcode_fx07_and_fx15() {
+            let mut emul = Emulator::new();
+            emul.delay_timer = 42;
+            emul.execute_opcode(0xF207).unwrap();
+            assert_eq!(emul.get_v_reg(2).unwrap(), 42);
+            emul.set_v_reg(3, 7).unwrap();
+            emul.execute_opcode(0xF315).unwrap();
+            assert_eq!(emul.delay_timer, 7);
+        }
+
+        /// Test Sound Timer = VX
+        #[test]
+        fn opcode_fx18() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 9).unwrap();
+            emul.execute_opcode(0xF218).unwrap();
+            assert_eq!(emul.sound_timer, 9);
+        }
+
+        /// Test I += VX
+        #[test]
+        fn opcode_fx1e() {
+            let mut emul = Emulator::new();
+            emul.i_reg = 0x300;
+            emul.set_v_reg(2, 5).unwrap();
+            emul.execute_opcode(0xF21E).unwrap();
+            assert_eq!(emul.i_reg, 0x305);
+        }
+
+        /// Test setting I to the font sprite address for the hex digit in VX
+        #[test]
+        fn opcode_fx29() {
+            let mut emul = Emulator::new();
+            emul.set_v_reg(2, 0xA).unwrap();
+            emul.execute_opcode(0xF229).unwrap();
+            assert_eq!(emul.i_reg, 0xA * 5);
+        }
+
+        /// Test the BCD encoding of 0 and 255
+        #[test]
+        fn opcode_fx33_bcd() {
+            let mut emul = Emulator::new();
+            emul.i_reg = 0x300;
+            emul.set_v_reg(2, 0).unwrap();
+            emul.execute_opcode(0xF233).unwrap();
+            assert_eq!(emul.ram[0x300..0x303], [0, 0, 0]);
+
+            emul.set_v_reg(2, 255).unwrap();
+            emul.execute_opcode(0xF233).unwrap();
+            assert_eq!(emul.ram[0x300..0x303], [2, 5, 5]);
+        }
+
+        /// Test a round-trip store (FX55) and load (FX65) of several registers
+        #[test]
+        fn opcode_fx55_fx65_round_trip() {
+            let mut emul = Emulator::new();
+            emul.i_reg = 0x300;
+            for reg in 0..=4 {
+                emul.set_v_reg(reg, (reg * 10) as u8).unwrap();
+            }
+            emul.execute_opcode(0xF455).unwrap();
+            assert_eq!(emul.i_reg, 0x305); // Classic quirk default increments I
+
+            let mut loaded = Emulator::new();
+            loaded.i_reg = 0x300;
+            loaded.ram[0x300..0x305].copy_from_slice(&emul.ram[0x300..0x305]);
+            loaded.execute_opcode(0xF465).unwrap();
+            for reg in 0..=4 {
+                assert_eq!(loaded.get_v_reg(reg).unwrap(), (reg * 10) as u8);
+            }
+            assert_eq!(loaded.i_reg, 0x305);
+        }
+
+        /// Test that FX55/FX65 leave I unchanged when load_store_increments_i is disabled
+        #[test]
+        fn opcode_fx55_fx65_no_increment_quirk() {
+            let mut emul = Emulator::new().with_quirks(Quirks {
+                load_store_increments_i: false,
+                ..Quirks::default()
+            });
+            emul.i_reg = 0x300;
+            emul.set_v_reg(0, 7).unwrap();
+            emul.execute_opcode(0xF055).unwrap();
+            assert_eq!(emul.i_reg, 0x300);
+        }
+
+        /// Test that FX33/FX55/FX65 return an error instead of panicking when I is too close to
+        /// the end of RAM to hold the written/read range
+        #[test]
+        fn opcode_fx33_fx55_fx65_memory_out_of_bounds() {
+            let mut emul = Emulator::new();
+            emul.i_reg = (RAM_SIZE - 2) as u16;
+            emul.set_v_reg(2, 255).unwrap();
+            assert!(matches!(
+                emul.execute_opcode(0xF233),
+                Err(EmulatorError::MemoryOutOfBounds(_))
+            ));
+
+            let mut emul = Emulator::new();
+            emul.i_reg = (RAM_SIZE - 1) as u16;
+            assert!(matches!(
+                emul.execute_opcode(0xF255),
+                Err(EmulatorError::MemoryOutOfBounds(_))
+            ));
+            assert!(matches!(
+                emul.execute_opcode(0xF265),
+                Err(EmulatorError::MemoryOutOfBounds(_))
+            ));
         }
     }
 }